@@ -12,19 +12,23 @@
 //! - `nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg`: first marker after character `29`
 //!
 use std::collections::HashSet;
+use std::io::{self, BufReader, Read};
 
-pub fn simple_solution(i: &[u8]) -> usize {
-    return i
-        .windows(14)
-        .position(|w| w.iter().collect::<HashSet<_>>().len() == 14)
-        .map(|i| i + 14)
-        .unwrap();
+pub fn simple_solution(i: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    i.windows(n)
+        .position(|w| w.iter().collect::<HashSet<_>>().len() == n)
+        .map(|i| i + n)
 }
 
 /// This is a faster solution that goes to next window as soon as it finds a duplicate character.
-pub fn faster_solution(i: &[u8]) -> usize {
-    return i
-        .windows(14)
+pub fn faster_solution(i: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    i.windows(n)
         .position(|w| {
             let mut hash_set = HashSet::new();
             for x in w {
@@ -34,8 +38,7 @@ pub fn faster_solution(i: &[u8]) -> usize {
             }
             return true;
         })
-        .map(|i| i + 14)
-        .unwrap();
+        .map(|i| i + n)
 }
 
 /// This is a faster solution that goes to next window as soon as it finds a duplicate character, but
@@ -59,11 +62,13 @@ pub fn faster_with_vec_solution(i: &[u8]) -> usize {
 
 /// This is a faster solution that goes to next window as soon as it finds a duplicate character, but
 /// uses an array instead of a hash set and vector.
-pub fn faster_with_array_solution(i: &[u8]) -> usize {
-    return i
-        .windows(14)
+pub fn faster_with_array_solution(i: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    i.windows(n)
         .position(|w| {
-            let mut arr = [0_u8; 14];
+            let mut arr = [0_u8; 256];
             let mut index = 0;
             for x in w {
                 for i in 0..index {
@@ -76,37 +81,68 @@ pub fn faster_with_array_solution(i: &[u8]) -> usize {
             }
             return true;
         })
-        .map(|i| i + 14)
-        .unwrap();
+        .map(|i| i + n)
 }
 
 /// This is a faster solution that uses a u32 number and bit manipulation to check for duplicates.
 /// This is first time uploaded by Benny
-pub fn benny_solution(input: &[u8]) -> usize {
+pub fn benny_solution(input: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
     let mut filter = 0_u32;
     input
         .iter() // Iterate over the input
-        .take(14 - 1) // Take the first 13 characters
+        .take(n - 1) // Take the first n - 1 characters
         .for_each(|c| filter ^= 1 << (c % 32)); // Set the bit corresponding to the character
-                                                // Basically we are setting the bit corresponding to a character (of first 13) to 1, if there is only one or odd number of that character, and 0 if there is even number of that character
+                                                // Basically we are setting the bit corresponding to a character (of first n - 1) to 1, if there is only one or odd number of that character, and 0 if there is even number of that character
 
     input
-        .windows(14)
+        .windows(n)
         .position(|w| {
             let first = w[0]; // First character of the window
             let last = w[w.len() - 1]; // Last character of the window
             filter ^= 1 << (last % 32); // Set the bit corresponding to the last character to 1 if it is not already in the window or 0 if it is already in the window
-            let res = filter.count_ones() == 14; // if there are 14 bits set to 1, then there are 14 distinct characters
+            let res = filter.count_ones() as usize == n; // if there are n bits set to 1, then there are n distinct characters
             filter ^= 1 << (first % 32); // Set the bit corresponding to the first character to opposite of what it was before, for next window (because next window shouldn't contain the first character)
             res
         })
-        .map(|i| i + 14)
-        .unwrap()
+        .map(|i| i + n)
 }
 
-pub fn david_a_perez_solution(input: &[u8]) -> usize {
+/// Same incremental XOR-in/XOR-out bit trick as `benny_solution`, but over a 256-bit mask
+/// (`[u64; 4]`) instead of a `u32`, so every byte value `0..=255` gets
+/// its own bit instead of sharing one of 32 bits with 7 others (e.g. `1 << (b'A' % 32) == 1 << (b'a' % 32)`).
+/// The word for byte `b` is `b >> 6` and the bit within that word is `b & 63`.
+pub fn full_range_bitmask_solution(input: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    let mut filter = [0_u64; 4];
+    input.iter().take(n - 1).for_each(|&c| {
+        filter[(c >> 6) as usize] ^= 1 << (c & 63);
+    });
+
+    input
+        .windows(n)
+        .position(|w| {
+            let first = w[0];
+            let last = w[w.len() - 1];
+            filter[(last >> 6) as usize] ^= 1 << (last & 63);
+            let distinct: u32 = filter.iter().map(|word| word.count_ones()).sum();
+            let res = distinct as usize == n;
+            filter[(first >> 6) as usize] ^= 1 << (first & 63);
+            res
+        })
+        .map(|i| i + n)
+}
+
+pub fn david_a_perez_solution(input: &[u8], n: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
     let mut idx = 0;
-    while let Some(slice) = input.get(idx..idx + 14) {
+    while let Some(slice) = input.get(idx..idx + n) {
         let mut state = 0_u32;
         if let Some(pos) = slice.iter().rposition(|byte| {
             let bit_idx = byte % 32;
@@ -116,10 +152,147 @@ pub fn david_a_perez_solution(input: &[u8]) -> usize {
         }) {
             idx += pos + 1;
         } else {
-            return idx + 14;
+            return Some(idx + n);
         }
     }
-    0
+    None
+}
+
+/// A genuine O(n) solution based on the "longest substring without repeating characters"
+/// technique: a two-pointer window where the left edge only ever jumps forward (straight past
+/// the earlier copy of a repeated byte), so every byte is read exactly once, as `end`.
+///
+/// Unlike the `windows`-based solutions above, this never restarts the distinctness check
+/// for overlapping windows, so its cost is linear in `input.len()` regardless of `n`.
+pub fn sliding_window_solution(input: &[u8], n: usize) -> Option<usize> {
+    let mut last_seen = [-1_isize; 256];
+    let mut start = 0_isize;
+
+    for (end, &byte) in input.iter().enumerate() {
+        let prev = last_seen[byte as usize];
+        if prev >= start {
+            start = prev + 1;
+        }
+        last_seen[byte as usize] = end as isize;
+
+        if end as isize - start + 1 == n as isize {
+            return Some(end + 1);
+        }
+    }
+
+    None
+}
+
+/// Lazily yields the end offset of every window of `n` distinct bytes in `input`, rather than
+/// stopping at the first one. Reuses the rolling `counts`/`distinct` bookkeeping from
+/// `find_marker_streaming` so each step of the iterator is O(1) amortized, and composes with
+/// the standard iterator adapters (`.nth()`, `.count()`, `.filter()`, ...).
+pub fn marker_positions(input: &[u8], n: usize) -> impl Iterator<Item = usize> + '_ {
+    MarkerPositions {
+        input,
+        n,
+        pos: 0,
+        counts: [0_u16; 256],
+        distinct: 0,
+    }
+}
+
+struct MarkerPositions<'a> {
+    input: &'a [u8],
+    n: usize,
+    pos: usize,
+    counts: [u16; 256],
+    distinct: usize,
+}
+
+impl<'a> Iterator for MarkerPositions<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.n == 0 {
+            return None;
+        }
+        while self.pos < self.input.len() {
+            let incoming = self.input[self.pos];
+
+            if self.pos >= self.n {
+                let outgoing = self.input[self.pos - self.n];
+                self.counts[outgoing as usize] -= 1;
+                if self.counts[outgoing as usize] == 0 {
+                    self.distinct -= 1;
+                }
+            }
+
+            self.counts[incoming as usize] += 1;
+            if self.counts[incoming as usize] == 1 {
+                self.distinct += 1;
+            }
+            self.pos += 1;
+
+            if self.pos >= self.n && self.distinct == self.n {
+                return Some(self.pos);
+            }
+        }
+
+        None
+    }
+}
+
+/// Streaming variant that scans a `Read` source one byte at a time instead of requiring the
+/// whole input to be buffered up front, so it can process piped stdin or files too large to
+/// hold in memory.
+///
+/// A `counts` table tracks how many times each byte occurs in the current n-byte window and
+/// `distinct` tracks how many of those counts are non-zero; once the window is full, the byte
+/// leaving it on the next step is evicted from `counts` before the new byte is admitted.
+pub fn find_marker_streaming<R: Read>(reader: R, n: usize) -> io::Result<Option<usize>> {
+    if n == 0 {
+        return Ok(None);
+    }
+    let mut reader = BufReader::new(reader);
+    let mut window = vec![0_u8; n];
+    let mut counts = [0_u16; 256];
+    let mut distinct = 0_usize;
+    let mut byte = [0_u8; 1];
+    let mut pos = 0_usize;
+
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            return Ok(None);
+        }
+        let incoming = byte[0];
+
+        if pos >= n {
+            let outgoing = window[pos % n];
+            counts[outgoing as usize] -= 1;
+            if counts[outgoing as usize] == 0 {
+                distinct -= 1;
+            }
+        }
+
+        window[pos % n] = incoming;
+        counts[incoming as usize] += 1;
+        if counts[incoming as usize] == 1 {
+            distinct += 1;
+        }
+        pos += 1;
+
+        if pos >= n && distinct == n {
+            return Ok(Some(pos));
+        }
+    }
+}
+
+/// Thin wrapper kept for backwards compatibility with callers that only ever
+/// looked for a start-of-packet marker (4 distinct characters).
+pub fn distinct_4(input: &[u8]) -> Option<usize> {
+    simple_solution(input, 4)
+}
+
+/// Thin wrapper kept for backwards compatibility with callers that only ever
+/// looked for a start-of-message marker (14 distinct characters).
+pub fn distinct_14(input: &[u8]) -> Option<usize> {
+    simple_solution(input, 14)
 }
 
 #[cfg(test)]
@@ -128,16 +301,22 @@ mod tests {
 
     #[test]
     fn test_simple_solution() {
-        assert_eq!(simple_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(simple_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(simple_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(simple_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+        assert_eq!(simple_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+        assert_eq!(
+            simple_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
     }
 
     #[test]
     fn test_faster_solution() {
-        assert_eq!(faster_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(faster_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(faster_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(faster_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+        assert_eq!(faster_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+        assert_eq!(
+            faster_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
     }
 
     #[test]
@@ -159,30 +338,145 @@ mod tests {
     #[test]
     fn test_faster_with_array_solution() {
         assert_eq!(
-            faster_with_array_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
-            19
+            faster_with_array_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+            Some(19)
         );
         assert_eq!(
-            faster_with_array_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz"),
-            23
+            faster_with_array_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14),
+            Some(23)
         );
         assert_eq!(
-            faster_with_array_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
-            29
+            faster_with_array_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
         );
     }
 
     #[test]
     fn test_benny_solution() {
-        assert_eq!(benny_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(benny_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(benny_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(benny_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+        assert_eq!(benny_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+        assert_eq!(
+            benny_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_zero_marker_length_returns_none_instead_of_panicking() {
+        assert_eq!(simple_solution(b"abc", 0), None);
+        assert_eq!(faster_solution(b"abc", 0), None);
+        assert_eq!(faster_with_array_solution(b"abc", 0), None);
+        assert_eq!(benny_solution(b"abc", 0), None);
+        assert_eq!(david_a_perez_solution(b"abc", 0), None);
     }
 
     #[test]
     fn test_david_a_perez_solution() {
-        assert_eq!(david_a_perez_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(david_a_perez_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(david_a_perez_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(
+            david_a_perez_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+            Some(19)
+        );
+        assert_eq!(
+            david_a_perez_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14),
+            Some(23)
+        );
+        assert_eq!(
+            david_a_perez_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_sliding_window_solution() {
+        assert_eq!(
+            sliding_window_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+            Some(19)
+        );
+        assert_eq!(
+            sliding_window_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14),
+            Some(23)
+        );
+        assert_eq!(
+            sliding_window_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
+        assert_eq!(sliding_window_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4), Some(7));
+    }
+
+    #[test]
+    fn test_marker_positions_yields_every_window() {
+        let positions: Vec<usize> = marker_positions(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4).collect();
+        assert_eq!(positions.first(), Some(&7));
+        assert_eq!(positions.last(), Some(&30));
+        assert_eq!(marker_positions(b"abc", 4).count(), 0);
+    }
+
+    #[test]
+    fn test_marker_positions_zero_marker_length_returns_none_instead_of_panicking() {
+        assert_eq!(marker_positions(b"abc", 0).collect::<Vec<_>>(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_full_range_bitmask_solution() {
+        assert_eq!(
+            full_range_bitmask_solution(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+            Some(19)
+        );
+        assert_eq!(
+            full_range_bitmask_solution(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14),
+            Some(23)
+        );
+        assert_eq!(
+            full_range_bitmask_solution(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_full_range_bitmask_solution_handles_case_collisions() {
+        // `benny_solution`/`david_a_perez_solution` use `c % 32`, so b'A' (65) and b'a' (97)
+        // both map to bit 1 and look like a duplicate even though they are distinct bytes.
+        assert_eq!(full_range_bitmask_solution(b"AaBb", 4), Some(4));
+        assert_eq!(benny_solution(b"AaBb", 4), None);
+    }
+
+    #[test]
+    fn test_full_range_bitmask_solution_zero_marker_length_returns_none_instead_of_panicking() {
+        assert_eq!(full_range_bitmask_solution(b"abc", 0), None);
+    }
+
+    #[test]
+    fn test_find_marker_streaming() {
+        assert_eq!(
+            find_marker_streaming(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_slice(), 14).unwrap(),
+            Some(19)
+        );
+        assert_eq!(
+            find_marker_streaming(b"bvwbjplbgvbhsrlpgdmjqwftvncz".as_slice(), 14).unwrap(),
+            Some(23)
+        );
+        assert_eq!(
+            find_marker_streaming(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_slice(), 14).unwrap(),
+            Some(29)
+        );
+        assert_eq!(
+            find_marker_streaming(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_slice(), 4).unwrap(),
+            Some(7)
+        );
+        assert_eq!(find_marker_streaming(b"abc".as_slice(), 4).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_marker_streaming_zero_marker_length_returns_none_instead_of_panicking() {
+        assert_eq!(find_marker_streaming(b"abc".as_slice(), 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_distinct_4_and_distinct_14_wrappers() {
+        assert_eq!(distinct_4(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Some(7));
+        assert_eq!(
+            distinct_14(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            Some(19)
+        );
     }
 }
@@ -13,9 +13,13 @@ fn main() {
 
     let messages = messages.iter().map(|s| s.as_bytes()).collect::<Vec<_>>();
 
-    check_timings("simple_solution", messages.clone(), simple_solution);
+    check_timings("simple_solution", messages.clone(), |m| {
+        simple_solution(m, 14).unwrap()
+    });
 
-    check_timings("Faster solution", messages.clone(), faster_solution);
+    check_timings("Faster solution", messages.clone(), |m| {
+        faster_solution(m, 14).unwrap()
+    });
 
     check_timings(
         "Faster solution with Vectors",
@@ -23,26 +27,22 @@ fn main() {
         faster_with_vec_solution,
     );
 
-    check_timings(
-        "Faster solution with Arrays",
-        messages.clone(),
-        faster_with_array_solution,
-    );
+    check_timings("Faster solution with Arrays", messages.clone(), |m| {
+        faster_with_array_solution(m, 14).unwrap()
+    });
 
-    check_timings(
-        "Benny's Solution using Bit Manipulation",
-        messages.clone(),
-        distinct_14::distinct_14_chars::benny_solution,
-    );
+    check_timings("Benny's Solution using Bit Manipulation", messages.clone(), |m| {
+        distinct_14::distinct_14_chars::benny_solution(m, 14).unwrap()
+    });
 
     check_timings(
         "David's Solution using Bit Manipulation and rposition",
         messages.clone(),
-        distinct_14::distinct_14_chars::david_a_perez_solution,
+        |m| distinct_14::distinct_14_chars::david_a_perez_solution(m, 14).unwrap(),
     );
 }
 
-fn check_timings(solution_name: &str, messages: Vec<&[u8]>, function: fn(&[u8]) -> usize) {
+fn check_timings(solution_name: &str, messages: Vec<&[u8]>, function: impl Fn(&[u8]) -> usize) {
     println!();
     println!("{:*^100}", format!(" {} ", solution_name));
     let start_time = Instant::now();